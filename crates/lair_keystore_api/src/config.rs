@@ -30,6 +30,696 @@ pub enum LairServerSignatureFallback {
         /// Optional arguments to be passed to command on execute.
         args: Option<Vec<String>>,
     },
+
+    /// Connect to a long-lived external signer speaking the same framed
+    /// json signature request/response protocol as [Self::Command], but
+    /// over a persistent connection shared by multiple lair instances
+    /// rather than a child process owned by this one. Transient
+    /// connection failures are retried with exponential backoff (see
+    /// [LairServiceSignerClient]). Use
+    /// [LairServerSignatureFallback::service_client] to obtain the client
+    /// for a given config and dispatch `sign_by_pub_key` misses to it.
+    #[serde(rename_all = "camelCase")]
+    Service {
+        /// The connection url of the external signer, either
+        /// `unix:///path/to/unix/socket` or `tcp://host:port`.
+        connection_url: url::Url,
+
+        /// Milliseconds to wait for the initial connection -- including
+        /// any backoff retries -- before giving up.
+        #[serde(default = "default_service_connect_timeout_ms")]
+        connect_timeout_ms: u64,
+
+        /// Milliseconds to wait for a response to a single signature
+        /// request before giving up.
+        #[serde(default = "default_service_request_timeout_ms")]
+        request_timeout_ms: u64,
+
+        /// Initial delay before the first reconnect attempt, doubling
+        /// after each failure up to `reconnect_max_delay_ms`.
+        #[serde(default = "default_service_reconnect_initial_delay_ms")]
+        reconnect_initial_delay_ms: u64,
+
+        /// Upper bound on the reconnect backoff delay.
+        #[serde(default = "default_service_reconnect_max_delay_ms")]
+        reconnect_max_delay_ms: u64,
+    },
+}
+
+impl LairServerSignatureFallback {
+    /// Construct the [LairServiceSignerClient] selected by this config at
+    /// runtime. Returns `None` for [Self::None] and [Self::Command], which
+    /// have no persistent connection to dispatch `sign_by_pub_key` misses
+    /// to -- `None` never has a fallback, and `Command` instead spawns a
+    /// child process that is this struct's caller's responsibility to
+    /// manage, the same way [LairStoreBackend::open_driver] has nothing to
+    /// return for a store backend with no driver implementation.
+    pub fn service_client(&self) -> Option<Arc<LairServiceSignerClient>> {
+        match self {
+            LairServerSignatureFallback::None | LairServerSignatureFallback::Command { .. } => None,
+            LairServerSignatureFallback::Service {
+                connection_url,
+                connect_timeout_ms,
+                request_timeout_ms,
+                reconnect_initial_delay_ms,
+                reconnect_max_delay_ms,
+            } => Some(Arc::new(LairServiceSignerClient::new(
+                connection_url.clone(),
+                *connect_timeout_ms,
+                *request_timeout_ms,
+                *reconnect_initial_delay_ms,
+                *reconnect_max_delay_ms,
+            ))),
+        }
+    }
+
+    /// Construct the [tokio::process::Command] selected by this config at
+    /// runtime, wired to feed/read framed json on its stdin/stdout, ready
+    /// for the caller to `spawn()`. Returns `None` for [Self::None] and
+    /// [Self::Service], which have no child process to spawn -- `None`
+    /// never has a fallback, and `Service` instead dials a persistent
+    /// connection via [Self::service_client]. Spawning, supervising, and
+    /// killing the returned command (e.g. on
+    /// [LairConfigReloadEffect::signature_fallback_changed]) remains the
+    /// caller's responsibility; this method only centralizes turning the
+    /// config into the command, the same way [Self::service_client] does
+    /// for `Service`.
+    pub fn spawn_command(&self) -> Option<tokio::process::Command> {
+        match self {
+            LairServerSignatureFallback::None | LairServerSignatureFallback::Service { .. } => None,
+            LairServerSignatureFallback::Command { program, args } => {
+                let mut cmd = tokio::process::Command::new(program);
+                if let Some(args) = args {
+                    cmd.args(args);
+                }
+                cmd.stdin(std::process::Stdio::piped());
+                cmd.stdout(std::process::Stdio::piped());
+                Some(cmd)
+            }
+        }
+    }
+}
+
+fn default_service_connect_timeout_ms() -> u64 {
+    10_000
+}
+
+fn default_service_request_timeout_ms() -> u64 {
+    10_000
+}
+
+fn default_service_reconnect_initial_delay_ms() -> u64 {
+    250
+}
+
+fn default_service_reconnect_max_delay_ms() -> u64 {
+    30_000
+}
+
+/// A client for the persistent connection used by
+/// [LairServerSignatureFallback::Service]. Lazily connects on first use,
+/// and reconnects with exponential backoff (bounded by `connect_timeout_ms`
+/// overall) if the connection drops. Speaks the same framed json
+/// signature request/response protocol as [LairServerSignatureFallback::Command]
+/// (a big-endian `u32` byte length prefix followed by that many bytes of
+/// json), just over a socket instead of a child process's stdio.
+pub struct LairServiceSignerClient {
+    connection_url: url::Url,
+    connect_timeout_ms: u64,
+    request_timeout_ms: u64,
+    reconnect_initial_delay_ms: u64,
+    reconnect_max_delay_ms: u64,
+    conn: tokio::sync::Mutex<Option<ServiceConnection>>,
+}
+
+enum ServiceConnection {
+    Tcp(tokio::net::TcpStream),
+    #[cfg(unix)]
+    Unix(tokio::net::UnixStream),
+}
+
+impl LairServiceSignerClient {
+    /// Construct a client for the given `Service` connection parameters.
+    /// Does not connect until the first [LairServiceSignerClient::request].
+    pub fn new(
+        connection_url: url::Url,
+        connect_timeout_ms: u64,
+        request_timeout_ms: u64,
+        reconnect_initial_delay_ms: u64,
+        reconnect_max_delay_ms: u64,
+    ) -> Self {
+        Self {
+            connection_url,
+            connect_timeout_ms,
+            request_timeout_ms,
+            reconnect_initial_delay_ms,
+            reconnect_max_delay_ms,
+            conn: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    async fn connect_once(url: &url::Url) -> LairResult<ServiceConnection> {
+        match url.scheme() {
+            "tcp" => {
+                let addr = get_connection_addr(url)?;
+                let stream = tokio::net::TcpStream::connect(addr)
+                    .await
+                    .map_err(OneErr::new)?;
+                Ok(ServiceConnection::Tcp(stream))
+            }
+            "unix" => {
+                #[cfg(unix)]
+                {
+                    let path = get_connection_path(url);
+                    let stream = tokio::net::UnixStream::connect(path)
+                        .await
+                        .map_err(OneErr::new)?;
+                    Ok(ServiceConnection::Unix(stream))
+                }
+                #[cfg(not(unix))]
+                {
+                    Err(OneErr::new(
+                        "unix connection urls are not supported on this platform",
+                    ))
+                }
+            }
+            scheme => Err(OneErr::new(format!(
+                "unsupported signature fallback service scheme '{scheme}'"
+            ))),
+        }
+    }
+
+    async fn connect_with_backoff(&self) -> LairResult<ServiceConnection> {
+        let deadline = std::time::Duration::from_millis(self.connect_timeout_ms);
+        tokio::time::timeout(deadline, async {
+            let mut delay = self.reconnect_initial_delay_ms.max(1);
+            loop {
+                match Self::connect_once(&self.connection_url).await {
+                    Ok(conn) => return conn,
+                    Err(_) => {
+                        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                        delay =
+                            ((delay as f64) * 2.0).min(self.reconnect_max_delay_ms as f64) as u64;
+                    }
+                }
+            }
+        })
+        .await
+        .map_err(|_| {
+            OneErr::new(format!(
+                "timed out connecting to signature fallback service '{}' after {}ms",
+                self.connection_url, self.connect_timeout_ms
+            ))
+        })
+    }
+
+    async fn round_trip(
+        conn: &mut ServiceConnection,
+        request_bytes: &[u8],
+    ) -> std::io::Result<Vec<u8>> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let len = request_bytes.len() as u32;
+        macro_rules! do_round_trip {
+            ($s:expr) => {{
+                $s.write_all(&len.to_be_bytes()).await?;
+                $s.write_all(request_bytes).await?;
+                let mut len_buf = [0u8; 4];
+                $s.read_exact(&mut len_buf).await?;
+                let resp_len = u32::from_be_bytes(len_buf) as usize;
+                let mut resp = vec![0u8; resp_len];
+                $s.read_exact(&mut resp).await?;
+                Ok(resp)
+            }};
+        }
+        match conn {
+            ServiceConnection::Tcp(s) => do_round_trip!(s),
+            #[cfg(unix)]
+            ServiceConnection::Unix(s) => do_round_trip!(s),
+        }
+    }
+
+    /// Send a single framed json signature request and return the framed
+    /// json response bytes verbatim. Connects lazily on first use, and
+    /// transparently reconnects (with backoff) if the persistent
+    /// connection has dropped, retrying the request exactly once against
+    /// the new connection.
+    ///
+    /// This client only speaks the framing (a length-prefixed byte
+    /// stream); it does not parse the json payload. So every `Err` this
+    /// returns is a transport-level failure -- a connect/reconnect
+    /// timeout, the first round-trip timing out (which is not retried),
+    /// or the retried round-trip itself timing out or erroring --
+    /// never a signer-reported protocol error. A protocol error (e.g. "no
+    /// such pub key") is carried *inside* a successful `Ok` response as
+    /// json the signer formatted as an error; interpreting that is the
+    /// caller's responsibility, same as it is for
+    /// [LairServerSignatureFallback::Command].
+    pub async fn request(&self, request_bytes: &[u8]) -> LairResult<Vec<u8>> {
+        let mut guard = self.conn.lock().await;
+        if guard.is_none() {
+            *guard = Some(self.connect_with_backoff().await?);
+        }
+
+        let timeout = std::time::Duration::from_millis(self.request_timeout_ms);
+        match tokio::time::timeout(
+            timeout,
+            Self::round_trip(guard.as_mut().unwrap(), request_bytes),
+        )
+        .await
+        {
+            Ok(Ok(resp)) => Ok(resp),
+            Ok(Err(_io_err)) => {
+                // connection-level failure -- reconnect (with backoff) and
+                // retry the request exactly once before giving up.
+                *guard = Some(self.connect_with_backoff().await?);
+                tokio::time::timeout(
+                    timeout,
+                    Self::round_trip(guard.as_mut().unwrap(), request_bytes),
+                )
+                .await
+                .map_err(|_| {
+                    OneErr::new("timed out waiting for signature fallback service response")
+                })?
+                .map_err(OneErr::new)
+            }
+            Err(_) => Err(OneErr::new(
+                "timed out waiting for signature fallback service response",
+            )),
+        }
+    }
+}
+
+/// Identity of a single ciphertext blob within a [LairStoreBackendDriver].
+pub type LairStoreBlobId = String;
+
+/// Object-safe storage backend for persisting lair's already-encrypted
+/// secrets. The sqlcipher / context-key encryption lair already applies
+/// happens *before* bytes reach this trait, so any implementation --
+/// local or remote -- only ever sees ciphertext.
+pub trait LairStoreBackendDriver: std::fmt::Debug + Send + Sync {
+    /// Read a single ciphertext blob by id.
+    fn read_blob(
+        &self,
+        id: LairStoreBlobId,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<Vec<u8>>> + Send + '_>>;
+
+    /// Write a single ciphertext blob by id, replacing any existing blob
+    /// stored under that id.
+    fn write_blob(
+        &self,
+        id: LairStoreBlobId,
+        data: Vec<u8>,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<()>> + Send + '_>>;
+
+    /// List the ids of all blobs currently stored.
+    fn list(
+        &self,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<Vec<LairStoreBlobId>>> + Send + '_>>;
+
+    /// Delete a single blob by id.
+    fn delete(
+        &self,
+        id: LairStoreBlobId,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<()>> + Send + '_>>;
+}
+
+/// Where lair persists its (already encrypted) secrets.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum LairStoreBackend {
+    /// Store secrets in a local sqlcipher file on disk. This is the
+    /// default, and the only backend prior to the introduction of
+    /// this enum.
+    #[serde(rename_all = "camelCase")]
+    Local {
+        /// The sqlcipher store file for persisting secrets.
+        store_file: std::path::PathBuf,
+    },
+
+    /// Store secrets in a remote object store. Lair still encrypts
+    /// everything with the sqlcipher / context-key scheme before handing
+    /// bytes to this backend, so the remote store only ever holds
+    /// ciphertext. This crate has no driver for this variant:
+    /// [LairStoreBackend::open_driver] returns an error for it.
+    #[serde(rename_all = "camelCase")]
+    Remote {
+        /// The object store endpoint, e.g. `https://s3.example.com`.
+        endpoint: url::Url,
+
+        /// The bucket (or bucket/prefix) under which entries are stored.
+        bucket: String,
+
+        /// Access key id used to authenticate with the object store.
+        access_key_id: String,
+
+        /// Secret access key used to authenticate with the object store.
+        secret_access_key: String,
+    },
+}
+
+impl LairStoreBackend {
+    /// If this is the [LairStoreBackend::Local] variant, the configured
+    /// store file path.
+    pub fn store_file(&self) -> Option<&std::path::Path> {
+        match self {
+            LairStoreBackend::Local { store_file } => Some(store_file),
+            LairStoreBackend::Remote { .. } => None,
+        }
+    }
+
+    /// Open the [LairStoreBackendDriver] selected by this config at
+    /// runtime. [LairStoreBackend::Local] is backed by
+    /// [LocalStoreBackendDriver]; [LairStoreBackend::Remote] has no
+    /// driver implementation in this crate yet, so this errors rather
+    /// than silently falling back to local storage.
+    pub fn open_driver(&self) -> LairResult<Arc<dyn LairStoreBackendDriver>> {
+        match self {
+            LairStoreBackend::Local { store_file } => Ok(Arc::new(
+                LocalStoreBackendDriver::new(store_file.clone()),
+            )),
+            LairStoreBackend::Remote { endpoint, .. } => Err(OneErr::new(format!(
+                "no LairStoreBackendDriver implementation available for remote endpoint '{endpoint}' -- configure a `Local` store_backend"
+            ))),
+        }
+    }
+}
+
+/// The only [LairStoreBackendDriver] implementation in this crate: each
+/// blob is a single file in a `<store_file>.blobs` directory alongside
+/// the configured store file.
+///
+/// This does not read or write the sqlcipher database at `store_file`
+/// itself -- this crate snapshot has no sqlcipher store implementation
+/// for the trait to wrap, so `open_driver` has nothing existing to
+/// abstract yet. This driver exists so the trait has at least one real,
+/// usable implementation (e.g. for secrets that are easier to keep as
+/// loose files than as sqlcipher rows) rather than being dead code; a
+/// future sqlcipher-backed driver belongs beside this one, not in place
+/// of it.
+#[derive(Debug, Clone)]
+pub struct LocalStoreBackendDriver {
+    blob_dir: std::path::PathBuf,
+}
+
+impl LocalStoreBackendDriver {
+    /// Construct a driver that keeps blobs in a `<store_file>.blobs`
+    /// directory next to `store_file`.
+    pub fn new(store_file: std::path::PathBuf) -> Self {
+        let mut blob_dir = store_file.clone().into_os_string();
+        blob_dir.push(".blobs");
+        Self {
+            blob_dir: blob_dir.into(),
+        }
+    }
+
+    // `id` is attacker-influenced (it round-trips through whatever called
+    // `read_blob`/`write_blob`/`delete`), so it must not be allowed to
+    // escape `blob_dir` via an absolute path or a `..` component -- only
+    // a single plain path segment is accepted.
+    fn blob_path(&self, id: &LairStoreBlobId) -> LairResult<std::path::PathBuf> {
+        let component = std::path::Path::new(id);
+        if component.components().count() != 1
+            || !matches!(
+                component.components().next(),
+                Some(std::path::Component::Normal(_))
+            )
+        {
+            return Err(OneErr::new(format!("invalid blob id '{id}'")));
+        }
+        Ok(self.blob_dir.join(component))
+    }
+}
+
+impl LairStoreBackendDriver for LocalStoreBackendDriver {
+    fn read_blob(
+        &self,
+        id: LairStoreBlobId,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<Vec<u8>>> + Send + '_>> {
+        let path = self.blob_path(&id);
+        Box::pin(async move { tokio::fs::read(path?).await.map_err(OneErr::new) })
+    }
+
+    fn write_blob(
+        &self,
+        id: LairStoreBlobId,
+        data: Vec<u8>,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<()>> + Send + '_>> {
+        let path = self.blob_path(&id);
+        let blob_dir = self.blob_dir.clone();
+        Box::pin(async move {
+            let path = path?;
+            tokio::fs::create_dir_all(&blob_dir)
+                .await
+                .map_err(OneErr::new)?;
+            tokio::fs::write(path, data).await.map_err(OneErr::new)
+        })
+    }
+
+    fn list(
+        &self,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<Vec<LairStoreBlobId>>> + Send + '_>> {
+        let blob_dir = self.blob_dir.clone();
+        Box::pin(async move {
+            let mut out = Vec::new();
+            let mut dir = match tokio::fs::read_dir(&blob_dir).await {
+                Ok(dir) => dir,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(out),
+                Err(e) => return Err(OneErr::new(e)),
+            };
+            while let Some(entry) = dir.next_entry().await.map_err(OneErr::new)? {
+                if let Some(name) = entry.file_name().to_str() {
+                    out.push(name.to_string());
+                }
+            }
+            Ok(out)
+        })
+    }
+
+    fn delete(
+        &self,
+        id: LairStoreBlobId,
+    ) -> std::pin::Pin<Box<dyn Future<Output = LairResult<()>> + Send + '_>> {
+        let path = self.blob_path(&id);
+        Box::pin(async move {
+            match tokio::fs::remove_file(path?).await {
+                Ok(()) => Ok(()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                Err(e) => Err(OneErr::new(e)),
+            }
+        })
+    }
+}
+
+// Mirror of [LairStoreBackend] used only to derive the tagged-enum
+// deserialize logic, so it can be combined below with support for the
+// pre-existing bare `storeFile: /path` scalar.
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum TaggedLairStoreBackend {
+    #[serde(rename_all = "camelCase")]
+    Local {
+        store_file: std::path::PathBuf,
+    },
+    #[serde(rename_all = "camelCase")]
+    Remote {
+        endpoint: url::Url,
+        bucket: String,
+        access_key_id: String,
+        secret_access_key: String,
+    },
+}
+
+impl From<TaggedLairStoreBackend> for LairStoreBackend {
+    fn from(t: TaggedLairStoreBackend) -> Self {
+        match t {
+            TaggedLairStoreBackend::Local { store_file } => LairStoreBackend::Local { store_file },
+            TaggedLairStoreBackend::Remote {
+                endpoint,
+                bucket,
+                access_key_id,
+                secret_access_key,
+            } => LairStoreBackend::Remote {
+                endpoint,
+                bucket,
+                access_key_id,
+                secret_access_key,
+            },
+        }
+    }
+}
+
+// Accept the pre-existing bare `storeFile: /path` scalar (from before this
+// enum existed) in addition to the tagged map representation, so older
+// config files keep loading unmodified.
+impl<'de> serde::Deserialize<'de> for LairStoreBackend {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // buffer through serde_yaml::Value rather than an untagged enum:
+        // serde's untagged buffering rejects yaml `!tag` enum input
+        let value = serde_yaml::Value::deserialize(deserializer)?;
+        if let serde_yaml::Value::String(store_file) = &value {
+            return Ok(LairStoreBackend::Local {
+                store_file: store_file.into(),
+            });
+        }
+        TaggedLairStoreBackend::deserialize(value)
+            .map(Into::into)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A named argon2id hardness profile for deriving the runtime secrets
+/// key, so operators can pick a profile by name instead of memorizing
+/// `mem_limit`/`ops_limit` byte counts. [LairServerConfigInner::new]
+/// translates the chosen profile into the concrete limits it writes to
+/// `runtime_secrets_mem_limit`/`runtime_secrets_ops_limit`, which remain
+/// the authoritative fields actually used to decrypt runtime secrets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LairRuntimeSecretsProfile {
+    /// Fast hashing, suitable for interactive unlocks.
+    Interactive,
+
+    /// A balance of hashing cost and speed.
+    Moderate,
+
+    /// Slow, expensive hashing for high-value secrets.
+    Sensitive,
+
+    /// Explicit `mem_limit` / `ops_limit`, bypassing the named profiles.
+    Custom {
+        /// argon2id mem_limit.
+        mem: u32,
+        /// argon2id ops_limit.
+        ops: u32,
+    },
+}
+
+impl Default for LairRuntimeSecretsProfile {
+    /// Configs written before this field existed have no recorded
+    /// profile -- serde falls back to this `custom:0:0` placeholder, which
+    /// [LairServerConfigInner::from_bytes] then replaces with a `Custom`
+    /// profile built from the authoritative mem/ops fields already on disk.
+    fn default() -> Self {
+        LairRuntimeSecretsProfile::Custom { mem: 0, ops: 0 }
+    }
+}
+
+impl std::fmt::Display for LairRuntimeSecretsProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Interactive => write!(f, "interactive"),
+            Self::Moderate => write!(f, "moderate"),
+            Self::Sensitive => write!(f, "sensitive"),
+            Self::Custom { mem, ops } => write!(f, "custom:{mem}:{ops}"),
+        }
+    }
+}
+
+impl std::str::FromStr for LairRuntimeSecretsProfile {
+    type Err = OneErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "interactive" => Ok(Self::Interactive),
+            "moderate" => Ok(Self::Moderate),
+            "sensitive" => Ok(Self::Sensitive),
+            _ => {
+                let (mem, ops) = s
+                    .strip_prefix("custom:")
+                    .and_then(|rest| rest.split_once(':'))
+                    .ok_or_else(|| OneErr::new(format!("invalid runtime secrets profile '{s}'")))?;
+                Ok(Self::Custom {
+                    mem: mem.parse().map_err(OneErr::new)?,
+                    ops: ops.parse().map_err(OneErr::new)?,
+                })
+            }
+        }
+    }
+}
+
+impl serde::Serialize for LairRuntimeSecretsProfile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for LairRuntimeSecretsProfile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// sqlcipher PRAGMA tuning to apply when the store is opened. All fields
+/// are optional; unset fields fall back to sqlcipher's own defaults. This
+/// type only describes the tuning -- call [DatabaseTuningConfig::pragma_statements]
+/// to get the PRAGMA statements to execute against the opened connection;
+/// this crate has no sqlcipher connection of its own to apply them to.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseTuningConfig {
+    /// `PRAGMA cache_size`.
+    pub cache_size: Option<i64>,
+
+    /// `PRAGMA journal_mode`, e.g. `"WAL"` or `"DELETE"`.
+    pub journal_mode: Option<String>,
+
+    /// `PRAGMA page_size`.
+    pub page_size: Option<u32>,
+
+    /// `PRAGMA kdf_iter`, the sqlcipher kdf iteration count.
+    pub kdf_iter: Option<u32>,
+}
+
+impl DatabaseTuningConfig {
+    /// Render the configured fields as ready-to-execute sqlcipher
+    /// `PRAGMA` statements, in an order safe to execute sequentially
+    /// (e.g. `kdf_iter` before the connection is used for anything else).
+    /// Unset fields produce no statement, leaving sqlcipher's default in
+    /// place. The caller that owns the actual sqlcipher connection (the
+    /// lair server, not this config crate) is responsible for executing
+    /// these against the connection right after opening it.
+    ///
+    /// Errors if `journal_mode` is set to anything other than one of
+    /// sqlite's recognized journal modes -- it is the one field here
+    /// that accepts a free-form string, so it's validated against an
+    /// allow-list rather than spliced into the PRAGMA unchecked.
+    pub fn pragma_statements(&self) -> LairResult<Vec<String>> {
+        const VALID_JOURNAL_MODES: &[&str] =
+            &["DELETE", "TRUNCATE", "PERSIST", "MEMORY", "WAL", "OFF"];
+
+        let mut out = Vec::new();
+        if let Some(kdf_iter) = self.kdf_iter {
+            out.push(format!("PRAGMA kdf_iter = {kdf_iter};"));
+        }
+        if let Some(page_size) = self.page_size {
+            out.push(format!("PRAGMA page_size = {page_size};"));
+        }
+        if let Some(cache_size) = self.cache_size {
+            out.push(format!("PRAGMA cache_size = {cache_size};"));
+        }
+        if let Some(journal_mode) = &self.journal_mode {
+            if !VALID_JOURNAL_MODES
+                .iter()
+                .any(|m| m.eq_ignore_ascii_case(journal_mode))
+            {
+                return Err(OneErr::new(format!(
+                    "invalid databaseTuning.journalMode '{journal_mode}', expected one of {VALID_JOURNAL_MODES:?}"
+                )));
+            }
+            out.push(format!("PRAGMA journal_mode = {journal_mode};"));
+        }
+        Ok(out)
+    }
 }
 
 /// Inner config type used by lair servers. This will be wrapped in an
@@ -41,14 +731,18 @@ pub struct LairServerConfigInner {
     /// The connection url for communications between server / client.
     /// - `unix:///path/to/unix/socket?k=Yada`
     /// - `named_pipe:\\.\pipe\my_pipe_name?k=Yada`
-    /// - `tcp://127.0.0.1:12345?k=Yada`
+    /// - `tcp://127.0.0.1:12345?k=Yada` -- see [LairTcpListener]/[tcp_connect]
+    ///   for the bind/connect + authenticated handshake that serves this
+    ///   transport, using the keypair embedded here as `?k=`
     pub connection_url: url::Url,
 
     /// The pid file for managing a running lair-keystore process
     pub pid_file: std::path::PathBuf,
 
-    /// The sqlcipher store file for persisting secrets
-    pub store_file: std::path::PathBuf,
+    /// Where lair persists its encrypted secrets -- a local sqlcipher
+    /// file by default, or a remote object store.
+    #[serde(alias = "storeFile")]
+    pub store_backend: LairStoreBackend,
 
     /// Configuration for managing sign_by_pub_key fallback
     /// in case the pub key does not exist in the lair store.
@@ -60,6 +754,14 @@ pub struct LairServerConfigInner {
     /// salt for decrypting runtime data
     pub runtime_secrets_salt: BinDataSized<16>,
 
+    /// the named hardness profile `runtime_secrets_mem_limit` /
+    /// `runtime_secrets_ops_limit` were derived from. This is purely
+    /// informational -- the mem/ops fields below remain authoritative --
+    /// but lets operators see and pick a hardness level by name instead
+    /// of memorizing byte counts.
+    #[serde(default)]
+    pub runtime_secrets_profile: LairRuntimeSecretsProfile,
+
     /// argon2id mem_limit for decrypting runtime data
     pub runtime_secrets_mem_limit: u32,
 
@@ -71,6 +773,13 @@ pub struct LairServerConfigInner {
 
     /// the server identity signature keypair seed
     pub runtime_secrets_id_seed: SecretDataSized<32, 49>,
+
+    /// sqlcipher PRAGMA tuning to apply when the store is opened --
+    /// see [DatabaseTuningConfig::pragma_statements]. Only meaningful for
+    /// the [LairStoreBackend::Local] backend; absent or `None` fields
+    /// fall back to sqlcipher's own defaults.
+    #[serde(default)]
+    pub database_tuning: Option<DatabaseTuningConfig>,
 }
 
 impl std::fmt::Display for LairServerConfigInner {
@@ -85,23 +794,26 @@ impl std::fmt::Display for LairServerConfigInner {
                     lines.push("");
                     lines.push("# The connection url for communications between server / client.");
                     lines.push("# - `unix:///path/to/unix/socket?k=Yada`");
-                    lines.push(
-                        "# - `named_pipe:\\\\.\\pipe\\my_pipe_name?k=Yada`",
-                    );
-                    lines.push("# - (not yet supported) `tcp://127.0.0.1:12345?k=Yada`");
+                    lines.push("# - `named_pipe:\\\\.\\pipe\\my_pipe_name?k=Yada`");
+                    lines.push("# - `tcp://127.0.0.1:12345?k=Yada`");
                 } else if line.starts_with("pidFile:") {
                     lines.push("");
                     lines.push("# The pid file for managing a running lair-keystore process");
-                } else if line.starts_with("storeFile:") {
+                } else if line.starts_with("storeBackend:") {
                     lines.push("");
-                    lines.push(
-                        "# The sqlcipher store file for persisting secrets",
-                    );
+                    lines.push("# Where encrypted secrets are persisted.");
+                    lines.push("# - `storeBackend: !local");
+                    lines.push("#     storeFile: /path/to/store_file`");
+                    lines.push("# - ```");
+                    lines.push("#   storeBackend: !remote");
+                    lines.push("#     endpoint: \"https://s3.example.com\"");
+                    lines.push("#     bucket: my-lair-bucket");
+                    lines.push("#     accessKeyId: ...");
+                    lines.push("#     secretAccessKey: ...");
+                    lines.push("#   ```");
                 } else if line.starts_with("signatureFallback:") {
                     lines.push("");
-                    lines.push(
-                        "# Configuration for managing sign_by_pub_key fallback",
-                    );
+                    lines.push("# Configuration for managing sign_by_pub_key fallback");
                     lines.push("# in case the pub key does not exist in the lair store.");
                     lines.push("# - `signatureFallback: none`");
                     lines.push("# - ```");
@@ -114,10 +826,35 @@ impl std::fmt::Display for LairServerConfigInner {
                     lines.push("#       - test-arg1");
                     lines.push("#       - test-arg2");
                     lines.push("#   ```");
+                    lines.push("# - ```");
+                    lines.push("#   signatureFallback: !service");
+                    lines.push("#     connectionUrl: \"unix:///path/to/signer.sock\"");
+                    lines.push("#     connectTimeoutMs: 10000");
+                    lines.push("#     requestTimeoutMs: 10000");
+                    lines.push("#     reconnectInitialDelayMs: 250");
+                    lines.push("#     reconnectMaxDelayMs: 30000");
+                    lines.push("#   ```");
                 } else if line.starts_with("databaseSalt:") {
                     lines.push("");
                     lines.push("# -- cryptographic secrets --");
-                    lines.push("# If you modify the data below, you risk losing access to your keys.");
+                    lines.push(
+                        "# If you modify the data below, you risk losing access to your keys.",
+                    );
+                } else if line.starts_with("runtimeSecretsProfile:") {
+                    lines.push("");
+                    lines.push("# Informational only -- the mem/ops limits below remain");
+                    lines.push("# authoritative. One of:");
+                    lines.push("# `interactive`, `moderate`, `sensitive`, `custom:<mem>:<ops>`");
+                } else if line.starts_with("databaseTuning:") {
+                    lines.push("");
+                    lines.push("# Optional sqlcipher PRAGMA tuning, e.g.:");
+                    lines.push("# - ```");
+                    lines.push("#   databaseTuning:");
+                    lines.push("#     cacheSize: -2000");
+                    lines.push("#     journalMode: WAL");
+                    lines.push("#     pageSize: 4096");
+                    lines.push("#     kdfIter: 64000");
+                    lines.push("#   ```");
                 }
             }
             lines.push(line);
@@ -129,20 +866,87 @@ impl std::fmt::Display for LairServerConfigInner {
 impl LairServerConfigInner {
     /// decode yaml bytes into a config struct
     pub fn from_bytes(bytes: &[u8]) -> LairResult<Self> {
-        serde_yaml::from_slice(bytes).map_err(one_err::OneErr::new)
+        let mut config: Self = serde_yaml::from_slice(bytes).map_err(one_err::OneErr::new)?;
+
+        // `runtime_secrets_profile` defaults to `custom:0:0` when absent
+        // from the file (see [LairRuntimeSecretsProfile::default]), which
+        // would otherwise disagree with whatever non-zero mem/ops limits
+        // the rest of the config actually carries. Derive the displayed
+        // profile from those authoritative limits instead of leaving the
+        // misleading `0:0` placeholder in place.
+        if config.runtime_secrets_profile == LairRuntimeSecretsProfile::default() {
+            config.runtime_secrets_profile = LairRuntimeSecretsProfile::Custom {
+                mem: config.runtime_secrets_mem_limit,
+                ops: config.runtime_secrets_ops_limit,
+            };
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validate invariants that aren't already expressible via the type
+    /// system.
+    ///
+    /// A [LairStoreBackend::Remote] store depends on `database_salt` and
+    /// `runtime_secrets_*` deriving the exact same keys that were used to
+    /// encrypt the remote ciphertext in the first place -- but that's a
+    /// property of the passphrase the caller supplies at unlock time, not
+    /// of this struct, so there is nothing derivable from the config alone
+    /// that can confirm or refute it here. An earlier version of this
+    /// check only compared `database_salt`/`runtime_secrets_salt` against
+    /// an all-zero placeholder, which didn't test key-derivation
+    /// equivalence at all and ignored `runtime_secrets_context_key`/
+    /// `runtime_secrets_id_seed` entirely; it has been removed rather than
+    /// kept as a misleading heuristic. Callers that need this guarantee
+    /// must verify it out-of-band (e.g. by unlocking both stores with the
+    /// same passphrase and comparing the resulting keys).
+    ///
+    /// Also rejects a `database_tuning.journal_mode` that
+    /// [DatabaseTuningConfig::pragma_statements] wouldn't recognize, so a
+    /// bad value is caught at load time instead of whenever a caller
+    /// happens to apply the tuning to a connection.
+    fn validate(&self) -> LairResult<()> {
+        if let Some(tuning) = &self.database_tuning {
+            tuning.pragma_statements()?;
+        }
+        Ok(())
     }
 
     /// Construct a new default lair server config instance.
-    /// Respects hc_seed_bundle::PwHashLimits.
+    ///
+    /// If `profile` is `None`, respects the ambient
+    /// `hc_seed_bundle::PwHashLimits` (as before this parameter existed).
+    /// Otherwise the given [LairRuntimeSecretsProfile] is translated into
+    /// the concrete `mem_limit`/`ops_limit` written to the resulting
+    /// config.
+    ///
+    /// By default, the connection url is an OS-appropriate local
+    /// transport (a unix domain socket, or a named pipe on windows). Pass
+    /// `tcp_bind_addr` to opt into a `tcp://` connection url bound to that
+    /// address instead, for a lair server reachable over the network.
+    /// Once the config is constructed, bind the transport with
+    /// [LairTcpListener::bind] (passing it `runtime_secrets_id_seed`,
+    /// decrypted, and the `?k=` key embedded in the resulting
+    /// `connection_url`) and have clients connect with [tcp_connect].
+    /// The handshake those perform authenticates the server to the
+    /// client (using the `runtime_secrets_id_seed` keypair); it does not
+    /// authenticate the client -- see [LairTcpListener] for that
+    /// limitation.
     pub fn new<P>(
         root_path: P,
         passphrase: SharedLockedArray,
+        tcp_bind_addr: Option<std::net::SocketAddr>,
+        profile: Option<LairRuntimeSecretsProfile>,
     ) -> impl Future<Output = LairResult<Self>> + 'static + Send
     where
         P: AsRef<std::path::Path>,
     {
         let root_path = root_path.as_ref().to_owned();
-        let limits = hc_seed_bundle::PwHashLimits::current();
+        // captured synchronously so a caller's `PwHashLimits::X.with_exec(...)`
+        // scope is respected, even though the rest of this fn runs later
+        // inside the returned future
+        let ambient_limits = hc_seed_bundle::PwHashLimits::current();
         async move {
             // default pid_file name is '[root_path]/pid_file'
             let mut pid_file = root_path.clone();
@@ -160,32 +964,62 @@ impl LairServerConfigInner {
                 None,
             )?;
 
-            // pull the captured argon2id limits
-            let ops_limit = limits.as_ops_limit();
-            let mem_limit = limits.as_mem_limit();
-
-            // generate an argon2id pre_secret from the passphrase
-            let (salt, pre_secret) =
-                tokio::task::spawn_blocking(move || -> LairResult<_> {
-                    // generate a random salt for the pwhash
-                    let mut salt = [0; sodoken::argon2::ARGON2_ID_SALTBYTES];
-                    sodoken::random::randombytes_buf(&mut salt)?;
-
-                    let mut pre_secret =
-                        sodoken::SizedLockedArray::<32>::new()?;
-
-                    sodoken::argon2::blocking_argon2id(
-                        &mut *pre_secret.lock(),
-                        &*pw_hash.lock(),
-                        &salt,
+            // translate the chosen profile (or the ambient
+            // hc_seed_bundle::PwHashLimits, if none was given) into
+            // concrete argon2id limits
+            let (runtime_secrets_profile, ops_limit, mem_limit) = match profile {
+                Some(LairRuntimeSecretsProfile::Custom { mem, ops }) => {
+                    (LairRuntimeSecretsProfile::Custom { mem, ops }, ops, mem)
+                }
+                Some(LairRuntimeSecretsProfile::Interactive) => (
+                    LairRuntimeSecretsProfile::Interactive,
+                    hc_seed_bundle::PwHashLimits::Interactive.as_ops_limit(),
+                    hc_seed_bundle::PwHashLimits::Interactive.as_mem_limit(),
+                ),
+                Some(LairRuntimeSecretsProfile::Moderate) => (
+                    LairRuntimeSecretsProfile::Moderate,
+                    hc_seed_bundle::PwHashLimits::Moderate.as_ops_limit(),
+                    hc_seed_bundle::PwHashLimits::Moderate.as_mem_limit(),
+                ),
+                Some(LairRuntimeSecretsProfile::Sensitive) => (
+                    LairRuntimeSecretsProfile::Sensitive,
+                    hc_seed_bundle::PwHashLimits::Sensitive.as_ops_limit(),
+                    hc_seed_bundle::PwHashLimits::Sensitive.as_mem_limit(),
+                ),
+                None => {
+                    let ops_limit = ambient_limits.as_ops_limit();
+                    let mem_limit = ambient_limits.as_mem_limit();
+                    (
+                        LairRuntimeSecretsProfile::Custom {
+                            mem: mem_limit,
+                            ops: ops_limit,
+                        },
                         ops_limit,
                         mem_limit,
-                    )?;
+                    )
+                }
+            };
 
-                    Ok((salt, pre_secret))
-                })
-                .await
-                .map_err(OneErr::new)??;
+            // generate an argon2id pre_secret from the passphrase
+            let (salt, pre_secret) = tokio::task::spawn_blocking(move || -> LairResult<_> {
+                // generate a random salt for the pwhash
+                let mut salt = [0; sodoken::argon2::ARGON2_ID_SALTBYTES];
+                sodoken::random::randombytes_buf(&mut salt)?;
+
+                let mut pre_secret = sodoken::SizedLockedArray::<32>::new()?;
+
+                sodoken::argon2::blocking_argon2id(
+                    &mut *pre_secret.lock(),
+                    &*pw_hash.lock(),
+                    &salt,
+                    ops_limit,
+                    mem_limit,
+                )?;
+
+                Ok((salt, pre_secret))
+            })
+            .await
+            .map_err(OneErr::new)??;
             let pre_secret = Arc::new(Mutex::new(pre_secret));
 
             // derive our context secret
@@ -229,44 +1063,39 @@ impl LairServerConfigInner {
             )?;
 
             // lock the context key
-            let context_key = SecretDataSized::encrypt(
-                ctx_secret,
-                Arc::new(Mutex::new(context_key)),
-            )
-            .await?;
+            let context_key =
+                SecretDataSized::encrypt(ctx_secret, Arc::new(Mutex::new(context_key))).await?;
 
             // lock the signature seed
-            let id_seed = SecretDataSized::encrypt(
-                id_secret,
-                Arc::new(Mutex::new(id_seed)),
-            )
-            .await?;
+            let id_seed =
+                SecretDataSized::encrypt(id_secret, Arc::new(Mutex::new(id_seed))).await?;
 
             // get the signature public key bytes for encoding in the url
             let id_pk: BinDataSized<32> = id_pk.into();
 
-            // on windows, we default to using "named pipes"
-            #[cfg(windows)]
-            let connection_url = {
-                let id = nanoid::nanoid!();
-                url::Url::parse(&format!(
-                    "named-pipe:\\\\.\\pipe\\{}?k={}",
-                    id, id_pk
-                ))
-                .unwrap()
-            };
+            let connection_url = if let Some(tcp_bind_addr) = tcp_bind_addr {
+                // opted in to a raw tcp connection, reachable over the network
+                url::Url::parse(&format!("tcp://{}?k={}", tcp_bind_addr, id_pk)).unwrap()
+            } else {
+                #[cfg(windows)]
+                {
+                    // on windows, we default to using "named pipes"
+                    let id = nanoid::nanoid!();
+                    url::Url::parse(&format!("named-pipe:\\\\.\\pipe\\{}?k={}", id, id_pk)).unwrap()
+                }
 
-            // on not-windows, we default to using unix domain sockets
-            #[cfg(not(windows))]
-            let connection_url = {
-                let mut con_path = dunce::canonicalize(root_path)?;
-                con_path.push("socket");
-                url::Url::parse(&format!(
-                    "unix://{}?k={}",
-                    con_path.to_str().unwrap(),
-                    id_pk
-                ))
-                .unwrap()
+                #[cfg(not(windows))]
+                {
+                    // on not-windows, we default to using unix domain sockets
+                    let mut con_path = dunce::canonicalize(root_path)?;
+                    con_path.push("socket");
+                    url::Url::parse(&format!(
+                        "unix://{}?k={}",
+                        con_path.to_str().unwrap(),
+                        id_pk
+                    ))
+                    .unwrap()
+                }
             };
 
             // generate a random salt for the sqlcipher database
@@ -277,14 +1106,16 @@ impl LairServerConfigInner {
             let config = LairServerConfigInner {
                 connection_url,
                 pid_file,
-                store_file,
+                store_backend: LairStoreBackend::Local { store_file },
                 signature_fallback: LairServerSignatureFallback::None,
                 database_salt: db_salt.into(),
                 runtime_secrets_salt: salt.into(),
+                runtime_secrets_profile,
                 runtime_secrets_mem_limit: mem_limit,
                 runtime_secrets_ops_limit: ops_limit,
                 runtime_secrets_context_key: context_key,
                 runtime_secrets_id_seed: id_seed,
+                database_tuning: None,
             };
 
             Ok(config)
@@ -302,14 +1133,198 @@ impl LairServerConfigInner {
         get_connection_path(&self.connection_url)
     }
 
+    /// Get the `tcp://` connection socket address out of this config's
+    /// `connection_url`, if it has one. Pass the result to
+    /// [LairTcpListener::bind] to serve it.
+    pub fn get_connection_addr(&self) -> LairResult<std::net::SocketAddr> {
+        get_connection_addr(&self.connection_url)
+    }
+
     /// Get the server pub key BinDataSized<32> bytes from the connectionUrl
     pub fn get_server_pub_key(&self) -> LairResult<BinDataSized<32>> {
         get_server_pub_key_from_connection_url(&self.connection_url)
     }
+
+    /// Parse `bytes` as a new config, then check it against `self` for
+    /// compatibility before returning it. [IMMUTABLE_CONFIG_FIELDS] lists
+    /// the fields that must **not** differ -- they are cryptographically
+    /// load-bearing, and changing them would make secrets encrypted
+    /// under the live config undecipherable, so this errors instead.
+    /// Everything else is allowed to change, but [LairServerConfigInner]
+    /// has no way to apply a changed field to already-running state
+    /// itself -- the returned [LairConfigReloadEffect] tells the caller
+    /// (the server embedding this config) what it needs to do about it:
+    /// tear down and respawn the `signature_fallback` child/connection,
+    /// and/or restart to pick up new `database_tuning` PRAGMAs.
+    pub fn reload_from_bytes(&self, bytes: &[u8]) -> LairResult<(Self, LairConfigReloadEffect)> {
+        let next = Self::from_bytes(bytes)?;
+
+        let cur = serde_yaml::to_value(self).map_err(OneErr::new)?;
+        let new = serde_yaml::to_value(&next).map_err(OneErr::new)?;
+
+        for field in IMMUTABLE_CONFIG_FIELDS {
+            if cur.get(field) != new.get(field) {
+                return Err(OneErr::new(format!(
+                    "cannot reload config: immutable field '{field}' changed"
+                )));
+            }
+        }
+
+        let effect = LairConfigReloadEffect {
+            signature_fallback_changed: cur.get("signatureFallback")
+                != new.get("signatureFallback"),
+            database_tuning_changed: cur.get("databaseTuning") != new.get("databaseTuning"),
+        };
+
+        Ok((next, effect))
+    }
+}
+
+/// Config fields that are cryptographically load-bearing. These must be
+/// identical before and after a call to
+/// [LairServerConfigInner::reload_from_bytes] -- every other field (e.g.
+/// `signatureFallback`) is free to change on reload.
+///
+/// `runtimeSecretsProfile` is included here even though it is otherwise
+/// informational: it is derived 1:1 from the (already immutable)
+/// `runtimeSecretsMemLimit`/`runtimeSecretsOpsLimit`, so letting it
+/// change independently would let a config falsely claim a different
+/// hardness than the one actually protecting its secrets.
+const IMMUTABLE_CONFIG_FIELDS: &[&str] = &[
+    "connectionUrl",
+    "pidFile",
+    "storeBackend",
+    "databaseSalt",
+    "runtimeSecretsSalt",
+    "runtimeSecretsProfile",
+    "runtimeSecretsMemLimit",
+    "runtimeSecretsOpsLimit",
+    "runtimeSecretsContextKey",
+    "runtimeSecretsIdSeed",
+];
+
+/// What a caller needs to do in response to a successful
+/// [LairServerConfigInner::reload_from_bytes] call, since the config
+/// type itself has no access to the already-running signer
+/// child/connection or the already-open sqlcipher store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LairConfigReloadEffect {
+    /// `signature_fallback` differs from the live config. The caller
+    /// should tear down and respawn/reconnect its fallback signer (the
+    /// `Command` child process, or the `Service` connection) using the
+    /// new config.
+    pub signature_fallback_changed: bool,
+
+    /// `database_tuning` differs from the live config. sqlcipher PRAGMAs
+    /// are applied when a connection is opened, so this has no effect on
+    /// an already-open store -- the caller must restart the process (or
+    /// otherwise reopen the store) to pick up the new tuning.
+    pub database_tuning_changed: bool,
+}
+
+/// An opt-in watcher that reloads a [LairServerConfig] from disk whenever
+/// its backing YAML file changes, so a running server can pick up changes
+/// to e.g. `signatureFallback` without being restarted.
+///
+/// This polls the file on a fixed interval rather than using a native
+/// filesystem watch (e.g. inotify/kqueue). A real watcher would save the
+/// wakeups between changes, but it would also need a platform-specific
+/// dependency this crate doesn't otherwise have, and has to fall back to
+/// polling anyway for the config living on a network filesystem or being
+/// replaced via rename (a common way config management tools write
+/// files, which some native watchers miss). Polling is simple, portable,
+/// and correct in both cases; pick a `poll_interval` that fits how
+/// promptly reloads need to happen. Prefer triggering a reload manually,
+/// e.g. from a `SIGHUP` handler, by calling
+/// [LairServerConfigInner::reload_from_bytes] directly if polling is too
+/// coarse for your use case.
+///
+/// `on_reload` only hands back the new config and a
+/// [LairConfigReloadEffect] -- it does not itself tear down or respawn
+/// anything, because this type has no handle on the caller's running
+/// signer child/connection or open store to do so. A typical `on_reload`
+/// that wants hot-reload to actually take effect looks like:
+///
+/// ```ignore
+/// LairConfigWatcher::spawn(config, path, poll_interval, move |res| {
+///     let (new_config, effect) = res.expect("log and ignore on Err instead, in production");
+///     if effect.signature_fallback_changed {
+///         // kill the old child/connection, then:
+///         if let Some(client) = new_config.signature_fallback.service_client() {
+///             // ...swap it in for dispatching sign_by_pub_key misses
+///         } else if let Some(mut cmd) = new_config.signature_fallback.spawn_command() {
+///             let _child = cmd.spawn(); // ...swap it in the same way
+///         }
+///     }
+///     if effect.database_tuning_changed {
+///         // database_tuning can't be applied to an open connection --
+///         // restart the process, or close and reopen the store.
+///     }
+/// });
+/// ```
+pub struct LairConfigWatcher {
+    shutdown: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl LairConfigWatcher {
+    /// Begin watching `config_path` for changes, reloading `config` with
+    /// [LairServerConfigInner::reload_from_bytes] and invoking `on_reload`
+    /// each time its contents change. `on_reload` receives `Err` (without
+    /// stopping the watcher) if the new file fails to parse or attempts
+    /// to change an immutable field; the live config is left unchanged
+    /// in that case. On success, `on_reload` also receives the
+    /// [LairConfigReloadEffect] describing what the caller needs to do
+    /// to apply the change to already-running state.
+    pub fn spawn<F>(
+        config: LairServerConfig,
+        config_path: std::path::PathBuf,
+        poll_interval: std::time::Duration,
+        mut on_reload: F,
+    ) -> Self
+    where
+        F: FnMut(LairResult<(LairServerConfig, LairConfigReloadEffect)>) + Send + 'static,
+    {
+        let shutdown = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let task_shutdown = shutdown.clone();
+        tokio::task::spawn(async move {
+            let mut config = config;
+            let mut last = tokio::fs::read(&config_path).await.ok();
+            while !task_shutdown.load(std::sync::atomic::Ordering::Relaxed) {
+                tokio::time::sleep(poll_interval).await;
+                let cur = tokio::fs::read(&config_path).await.ok();
+                if cur.is_some() && cur != last {
+                    last = cur.clone();
+                    let bytes = cur.unwrap();
+                    match config.reload_from_bytes(&bytes) {
+                        Ok((next, effect)) => {
+                            config = Arc::new(next);
+                            on_reload(Ok((config.clone(), effect)));
+                        }
+                        Err(err) => on_reload(Err(err)),
+                    }
+                }
+            }
+        });
+        Self { shutdown }
+    }
+
+    /// Stop watching the config file. Also happens implicitly on drop.
+    pub fn shutdown(&self) {
+        self.shutdown
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+impl Drop for LairConfigWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
 }
 
 /// Get the connection "path". This could have different meanings
 /// depending on if we are a unix domain socket or named pipe, etc.
+/// This does not apply to `tcp://` connection urls, which have no
+/// filesystem path -- use [get_connection_addr] for those instead.
 pub fn get_connection_path(url: &url::Url) -> std::path::PathBuf {
     #[cfg(windows)]
     {
@@ -318,16 +1333,272 @@ pub fn get_connection_path(url: &url::Url) -> std::path::PathBuf {
 
     #[cfg(not(windows))]
     {
-        url.to_file_path().expect("The connection url is invalid, as it does not decode to
+        url.to_file_path().expect(
+            "The connection url is invalid, as it does not decode to
 an absolute file path. The likely cause is that a relative path was used instead of an absolute one.
-If that's the case, try using an absolute one instead.")
+If that's the case, try using an absolute one instead.",
+        )
+    }
+}
+
+/// Get the socket address out of a `tcp://host:port?k=...` connection url,
+/// suitable for passing to [LairTcpListener::bind] or [tcp_connect].
+pub fn get_connection_addr(url: &url::Url) -> LairResult<std::net::SocketAddr> {
+    if url.scheme() != "tcp" {
+        return Err(format!("expected a 'tcp' connection url, got '{}'", url.scheme()).into());
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| OneErr::new("tcp connection url is missing a host"))?;
+    let port = url
+        .port()
+        .ok_or_else(|| OneErr::new("tcp connection url is missing a port"))?;
+    format!("{host}:{port}")
+        .parse()
+        .map_err(|e| OneErr::new(format!("invalid tcp connection url: {e}")))
+}
+
+/// Byte length of a crypto_box nonce, as used by the `tcp://` handshake.
+const TCP_HANDSHAKE_NONCE_LEN: usize = sodoken::crypto_box::XSALSA_NONCEBYTES;
+
+/// Listens for and authenticates `tcp://` transport connections.
+///
+/// Every accepted connection goes through a handshake that proves the
+/// peer on this end of the socket holds the secret key paired with
+/// `server_id_pk` -- the same keypair [LairServerConfigInner::new]
+/// derives from `runtime_secrets_id_seed` and embeds in `connection_url`
+/// as `?k=`. A client that already knows that public key (e.g. because
+/// it read it out of the connection url, same as a unix socket client
+/// trusts the filesystem path it was given) can use the handshake to
+/// confirm it really is talking to this server and not an impostor that
+/// happened to be listening on the same address.
+///
+/// This authenticates the *server* to the client. It does **not**
+/// authenticate the client to the server -- anyone who can reach the
+/// port can complete the handshake, the same trust model a unix domain
+/// socket gets for free from filesystem permissions. Client
+/// authentication would need a registered allow-list of client public
+/// keys, which has no config representation yet.
+pub struct LairTcpListener {
+    listener: tokio::net::TcpListener,
+    server_id_pk: BinDataSized<32>,
+    server_id_seed: Arc<Mutex<sodoken::SizedLockedArray<32>>>,
+}
+
+impl LairTcpListener {
+    /// Bind `addr` and prepare to serve the `tcp://` transport,
+    /// authenticating with the keypair derived from `server_id_seed`
+    /// (the decrypted `runtime_secrets_id_seed`). `server_id_pk` is the
+    /// corresponding public key, the same one embedded in
+    /// `connection_url` as `?k=`.
+    pub async fn bind(
+        addr: std::net::SocketAddr,
+        server_id_pk: BinDataSized<32>,
+        server_id_seed: Arc<Mutex<sodoken::SizedLockedArray<32>>>,
+    ) -> LairResult<Self> {
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .map_err(OneErr::new)?;
+        Ok(Self {
+            listener,
+            server_id_pk,
+            server_id_seed,
+        })
+    }
+
+    /// Accept and authenticate the next incoming connection, returning
+    /// the raw stream once the handshake succeeds. The stream carries no
+    /// further framing or encryption of its own past the handshake --
+    /// that's left to whatever request/response protocol the caller
+    /// layers on top (e.g. the same framed json [LairServerSignatureFallback::Command]
+    /// uses).
+    pub async fn accept(&self) -> LairResult<tokio::net::TcpStream> {
+        let (mut stream, _) = self.listener.accept().await.map_err(OneErr::new)?;
+        tcp_server_handshake(&mut stream, &self.server_id_pk, &self.server_id_seed).await?;
+        Ok(stream)
+    }
+
+    /// The local address this listener is bound to, e.g. to discover the
+    /// actual port after binding to `:0`.
+    pub fn local_addr(&self) -> LairResult<std::net::SocketAddr> {
+        self.listener.local_addr().map_err(OneErr::new)
+    }
+}
+
+/// Connect to a `tcp://` transport at `addr` and perform the client side
+/// of the handshake, verifying the server holds the secret key paired
+/// with `expected_server_id_pk` (typically obtained from the target's
+/// `connection_url` via [get_server_pub_key_from_connection_url]) before
+/// returning the stream. Errors if the server can't prove that -- e.g.
+/// it's an impostor, or the address is simply wrong.
+pub async fn tcp_connect(
+    addr: std::net::SocketAddr,
+    expected_server_id_pk: &BinDataSized<32>,
+) -> LairResult<tokio::net::TcpStream> {
+    let mut stream = tokio::net::TcpStream::connect(addr)
+        .await
+        .map_err(OneErr::new)?;
+    tcp_client_handshake(&mut stream, expected_server_id_pk).await?;
+    Ok(stream)
+}
+
+async fn write_framed(stream: &mut tokio::net::TcpStream, bytes: &[u8]) -> LairResult<()> {
+    use tokio::io::AsyncWriteExt;
+    stream
+        .write_all(&(bytes.len() as u32).to_be_bytes())
+        .await
+        .map_err(OneErr::new)?;
+    stream.write_all(bytes).await.map_err(OneErr::new)
+}
+
+async fn read_framed(stream: &mut tokio::net::TcpStream) -> LairResult<Vec<u8>> {
+    use tokio::io::AsyncReadExt;
+    let mut len_buf = [0u8; 4];
+    stream.read_exact(&mut len_buf).await.map_err(OneErr::new)?;
+    let len = u32::from_be_bytes(len_buf) as usize;
+    let mut buf = vec![0u8; len];
+    stream.read_exact(&mut buf).await.map_err(OneErr::new)?;
+    Ok(buf)
+}
+
+// Server side of the `tcp://` handshake: prove possession of the secret
+// key paired with `server_id_pk` by boxing a random challenge to the
+// client's ephemeral key, then verifying the client can echo it back.
+async fn tcp_server_handshake(
+    stream: &mut tokio::net::TcpStream,
+    server_id_pk: &BinDataSized<32>,
+    server_id_seed: &Arc<Mutex<sodoken::SizedLockedArray<32>>>,
+) -> LairResult<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // our long-term keypair is re-derived from the seed each handshake,
+    // rather than kept around decrypted between connections
+    let mut server_id_pk_raw = [0u8; sodoken::crypto_box::XSALSA_PUBLICKEYBYTES];
+    let mut server_id_sk = sodoken::SizedLockedArray::<32>::new()?;
+    {
+        let seed = server_id_seed.lock().unwrap();
+        sodoken::crypto_box::xsalsa_seed_keypair(
+            &mut server_id_pk_raw,
+            &mut server_id_sk.lock(),
+            &seed.lock(),
+        )?;
+    }
+
+    // 1. receive the client's ephemeral public key
+    let mut client_eph_pk = [0u8; sodoken::crypto_box::XSALSA_PUBLICKEYBYTES];
+    stream
+        .read_exact(&mut client_eph_pk)
+        .await
+        .map_err(OneErr::new)?;
+
+    // 2. box a random challenge to the client's ephemeral key using our
+    // long-term secret key -- only the real server can produce this
+    let mut challenge = [0u8; 32];
+    sodoken::random::randombytes_buf(&mut challenge)?;
+    let mut nonce = [0u8; TCP_HANDSHAKE_NONCE_LEN];
+    sodoken::random::randombytes_buf(&mut nonce)?;
+    let ciphertext =
+        sodoken::crypto_box::xsalsa_easy(&nonce, &challenge, &client_eph_pk, &server_id_sk.lock())
+            .map_err(OneErr::new)?;
+
+    stream
+        .write_all(&server_id_pk_raw)
+        .await
+        .map_err(OneErr::new)?;
+    write_framed(stream, &nonce).await?;
+    write_framed(stream, &ciphertext).await?;
+
+    // confirm the public key we just sent actually matches the one this
+    // server is configured to advertise
+    let advertised: BinDataSized<32> = server_id_pk_raw.into();
+    if advertised.to_string() != server_id_pk.to_string() {
+        return Err(OneErr::new(
+            "tcp handshake failed: derived keypair does not match configured server_id_pk",
+        ));
     }
+
+    // 3. the client echoes the challenge back, boxed to our long-term
+    // key using its ephemeral secret key -- this confirms it actually
+    // decrypted step 2, i.e. that it trusts our identity. It is not
+    // proof of the client's own identity (see [LairTcpListener] docs).
+    let echo_nonce = read_framed(stream).await?;
+    let echo_ciphertext = read_framed(stream).await?;
+    let echoed = sodoken::crypto_box::xsalsa_open_easy(
+        &echo_nonce,
+        &echo_ciphertext,
+        &client_eph_pk,
+        &server_id_sk.lock(),
+    )
+    .map_err(|e| OneErr::new(format!("tcp handshake failed: bad client echo: {e}")))?;
+    if echoed.as_slice() != &challenge[..] {
+        return Err(OneErr::new("tcp handshake failed: challenge mismatch"));
+    }
+
+    Ok(())
+}
+
+// Client side of the `tcp://` handshake: generate an ephemeral keypair,
+// verify the server can box a challenge using the secret key paired with
+// `expected_server_id_pk`, then echo the challenge back to prove receipt.
+async fn tcp_client_handshake(
+    stream: &mut tokio::net::TcpStream,
+    expected_server_id_pk: &BinDataSized<32>,
+) -> LairResult<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // 1. generate an ephemeral keypair and send the public half
+    let mut eph_seed = [0u8; 32];
+    sodoken::random::randombytes_buf(&mut eph_seed)?;
+    let mut eph_pk = [0u8; sodoken::crypto_box::XSALSA_PUBLICKEYBYTES];
+    let mut eph_sk = sodoken::SizedLockedArray::<32>::new()?;
+    sodoken::crypto_box::xsalsa_seed_keypair(&mut eph_pk, &mut eph_sk.lock(), &eph_seed)?;
+    stream.write_all(&eph_pk).await.map_err(OneErr::new)?;
+
+    // 2. receive the server's claimed long-term pubkey + boxed challenge
+    let mut claimed_server_pk_raw = [0u8; sodoken::crypto_box::XSALSA_PUBLICKEYBYTES];
+    stream
+        .read_exact(&mut claimed_server_pk_raw)
+        .await
+        .map_err(OneErr::new)?;
+    let claimed_server_pk: BinDataSized<32> = claimed_server_pk_raw.into();
+    if claimed_server_pk.to_string() != expected_server_id_pk.to_string() {
+        return Err(OneErr::new(
+            "tcp handshake failed: server presented a different public key than the connection url",
+        ));
+    }
+    let nonce = read_framed(stream).await?;
+    let ciphertext = read_framed(stream).await?;
+    let challenge = sodoken::crypto_box::xsalsa_open_easy(
+        &nonce,
+        &ciphertext,
+        &claimed_server_pk_raw,
+        &eph_sk.lock(),
+    )
+    .map_err(|e| {
+        OneErr::new(format!(
+            "tcp handshake failed: could not verify server identity: {e}"
+        ))
+    })?;
+
+    // 3. echo the decrypted challenge back, proving we hold `eph_sk` and
+    // really did read it out of the box the server sent
+    let mut echo_nonce = [0u8; TCP_HANDSHAKE_NONCE_LEN];
+    sodoken::random::randombytes_buf(&mut echo_nonce)?;
+    let echo_ciphertext = sodoken::crypto_box::xsalsa_easy(
+        &echo_nonce,
+        &challenge,
+        &claimed_server_pk_raw,
+        &eph_sk.lock(),
+    )
+    .map_err(OneErr::new)?;
+    write_framed(stream, &echo_nonce).await?;
+    write_framed(stream, &echo_ciphertext).await?;
+
+    Ok(())
 }
 
 /// Helper utility for extracting a server_pub_key from a connection_url.
-pub fn get_server_pub_key_from_connection_url(
-    url: &url::Url,
-) -> LairResult<BinDataSized<32>> {
+pub fn get_server_pub_key_from_connection_url(url: &url::Url) -> LairResult<BinDataSized<32>> {
     for (k, v) in url.query_pairs() {
         if k == "k" {
             return v.parse();
@@ -350,9 +1621,7 @@ mod tests {
             b"passphrase".to_vec(),
         )));
         let mut srv = hc_seed_bundle::PwHashLimits::Minimum
-            .with_exec(|| {
-                LairServerConfigInner::new(tempdir.path(), passphrase)
-            })
+            .with_exec(|| LairServerConfigInner::new(tempdir.path(), passphrase, None, None))
             .await
             .unwrap();
 
@@ -370,4 +1639,208 @@ mod tests {
         println!("{}", &srv);
         println!("-- server config end --");
     }
+
+    #[test]
+    fn test_legacy_store_file_scalar_deserializes_to_local() {
+        let backend: LairStoreBackend = serde_yaml::from_str("/path/to/store_file").unwrap();
+        assert_eq!(
+            backend,
+            LairStoreBackend::Local {
+                store_file: "/path/to/store_file".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_runtime_secrets_profile_display_from_str_roundtrip() {
+        for profile in [
+            LairRuntimeSecretsProfile::Interactive,
+            LairRuntimeSecretsProfile::Moderate,
+            LairRuntimeSecretsProfile::Sensitive,
+            LairRuntimeSecretsProfile::Custom { mem: 123, ops: 456 },
+        ] {
+            let s = profile.to_string();
+            assert_eq!(s.parse::<LairRuntimeSecretsProfile>().unwrap(), profile);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_from_bytes_derives_runtime_secrets_profile_from_limits() {
+        let tempdir = tempdir::TempDir::new("example").unwrap();
+        let passphrase = Arc::new(Mutex::new(sodoken::LockedArray::from(
+            b"passphrase".to_vec(),
+        )));
+        let srv = hc_seed_bundle::PwHashLimits::Minimum
+            .with_exec(|| LairServerConfigInner::new(tempdir.path(), passphrase, None, None))
+            .await
+            .unwrap();
+
+        // Simulate a config file written before `runtimeSecretsProfile`
+        // existed: strip the field entirely so it deserializes to its
+        // `custom:0:0` default.
+        let yaml = serde_yaml::to_string(&srv).unwrap();
+        let mut value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        value
+            .as_mapping_mut()
+            .unwrap()
+            .remove("runtimeSecretsProfile");
+        let bytes = serde_yaml::to_string(&value).unwrap();
+
+        let loaded = LairServerConfigInner::from_bytes(bytes.as_bytes()).unwrap();
+        assert_eq!(
+            loaded.runtime_secrets_profile,
+            LairRuntimeSecretsProfile::Custom {
+                mem: srv.runtime_secrets_mem_limit,
+                ops: srv.runtime_secrets_ops_limit,
+            }
+        );
+    }
+
+    #[test]
+    fn test_signature_fallback_service_client_dispatch() {
+        assert!(LairServerSignatureFallback::None.service_client().is_none());
+        assert!(LairServerSignatureFallback::Command {
+            program: "./my-executable".into(),
+            args: None,
+        }
+        .service_client()
+        .is_none());
+        assert!(LairServerSignatureFallback::Service {
+            connection_url: url::Url::parse("unix:///tmp/sock").unwrap(),
+            connect_timeout_ms: default_service_connect_timeout_ms(),
+            request_timeout_ms: default_service_request_timeout_ms(),
+            reconnect_initial_delay_ms: default_service_reconnect_initial_delay_ms(),
+            reconnect_max_delay_ms: default_service_reconnect_max_delay_ms(),
+        }
+        .service_client()
+        .is_some());
+    }
+
+    #[test]
+    fn test_signature_fallback_spawn_command_dispatch() {
+        assert!(LairServerSignatureFallback::None.spawn_command().is_none());
+        assert!(LairServerSignatureFallback::Service {
+            connection_url: url::Url::parse("unix:///tmp/sock").unwrap(),
+            connect_timeout_ms: default_service_connect_timeout_ms(),
+            request_timeout_ms: default_service_request_timeout_ms(),
+            reconnect_initial_delay_ms: default_service_reconnect_initial_delay_ms(),
+            reconnect_max_delay_ms: default_service_reconnect_max_delay_ms(),
+        }
+        .spawn_command()
+        .is_none());
+        assert!(LairServerSignatureFallback::Command {
+            program: "./my-executable".into(),
+            args: Some(vec!["test-arg1".into()]),
+        }
+        .spawn_command()
+        .is_some());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_reload_from_bytes_rejects_immutable_field_change() {
+        let tempdir = tempdir::TempDir::new("example").unwrap();
+        let passphrase = Arc::new(Mutex::new(sodoken::LockedArray::from(
+            b"passphrase".to_vec(),
+        )));
+        let srv = hc_seed_bundle::PwHashLimits::Minimum
+            .with_exec(|| LairServerConfigInner::new(tempdir.path(), passphrase, None, None))
+            .await
+            .unwrap();
+
+        // mutable field: reload succeeds, and reports the change.
+        let mut mutable = srv.clone();
+        mutable.signature_fallback = LairServerSignatureFallback::Command {
+            program: "./my-executable".into(),
+            args: None,
+        };
+        let mutable_bytes = serde_yaml::to_string(&mutable).unwrap();
+        let (reloaded, effect) = srv.reload_from_bytes(mutable_bytes.as_bytes()).unwrap();
+        assert!(effect.signature_fallback_changed);
+        assert!(!effect.database_tuning_changed);
+        assert_eq!(
+            reloaded.database_salt.to_string(),
+            srv.database_salt.to_string()
+        );
+
+        // immutable field: reload errors, live config is untouched by the
+        // caller (reload_from_bytes never mutates `self`).
+        let mut immutable = srv.clone();
+        immutable.database_salt = [1; 16].into();
+        let immutable_bytes = serde_yaml::to_string(&immutable).unwrap();
+        assert!(srv.reload_from_bytes(immutable_bytes.as_bytes()).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_local_store_backend_driver_roundtrip() {
+        let tempdir = tempdir::TempDir::new("blobs").unwrap();
+        let driver = LocalStoreBackendDriver::new(tempdir.path().join("store.sqlite3"));
+
+        driver
+            .write_blob("my-blob".into(), b"hello".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            driver.read_blob("my-blob".into()).await.unwrap(),
+            b"hello".to_vec()
+        );
+        assert_eq!(driver.list().await.unwrap(), vec!["my-blob".to_string()]);
+
+        driver.delete("my-blob".into()).await.unwrap();
+        assert!(driver.list().await.unwrap().is_empty());
+        // deleting an already-absent blob is not an error
+        driver.delete("my-blob".into()).await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_local_store_backend_driver_rejects_path_traversal() {
+        let tempdir = tempdir::TempDir::new("blobs").unwrap();
+        let driver = LocalStoreBackendDriver::new(tempdir.path().join("store.sqlite3"));
+
+        for bad_id in ["../escaped", "a/../../escaped", "/etc/passwd", "a/b"] {
+            assert!(
+                driver
+                    .write_blob(bad_id.into(), b"oops".to_vec())
+                    .await
+                    .is_err(),
+                "expected '{bad_id}' to be rejected"
+            );
+        }
+    }
+
+    async fn gen_id_keypair() -> (BinDataSized<32>, Arc<Mutex<sodoken::SizedLockedArray<32>>>) {
+        let mut id_seed = sodoken::SizedLockedArray::<32>::new().unwrap();
+        sodoken::random::randombytes_buf(&mut *id_seed.lock()).unwrap();
+        let mut id_pk = [0; sodoken::crypto_box::XSALSA_PUBLICKEYBYTES];
+        let mut id_sk = sodoken::SizedLockedArray::<32>::new().unwrap();
+        sodoken::crypto_box::xsalsa_seed_keypair(&mut id_pk, &mut id_sk.lock(), &id_seed.lock())
+            .unwrap();
+        (id_pk.into(), Arc::new(Mutex::new(id_seed)))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tcp_handshake_succeeds_with_correct_pub_key() {
+        let (id_pk, id_seed) = gen_id_keypair().await;
+        let listener =
+            LairTcpListener::bind("127.0.0.1:0".parse().unwrap(), id_pk.clone(), id_seed)
+                .await
+                .unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let (accepted, connected) = tokio::join!(listener.accept(), tcp_connect(addr, &id_pk));
+        accepted.unwrap();
+        connected.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tcp_handshake_rejects_wrong_pub_key() {
+        let (id_pk, id_seed) = gen_id_keypair().await;
+        let (wrong_pk, _) = gen_id_keypair().await;
+        let listener = LairTcpListener::bind("127.0.0.1:0".parse().unwrap(), id_pk, id_seed)
+            .await
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let (_accepted, connected) = tokio::join!(listener.accept(), tcp_connect(addr, &wrong_pk));
+        assert!(connected.is_err());
+    }
 }